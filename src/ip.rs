@@ -0,0 +1,183 @@
+/// Identifies which IP address family a value belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IpVersion {
+    V4,
+    V6,
+}
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for crate::ipv4::Ipv4Addr {}
+    impl Sealed for crate::ipv6::Ipv6Addr {}
+}
+
+/// Parameterizes the calculator over address width and backing integer, so
+/// the same next-power-of-two / increment logic can produce both IPv4 and
+/// IPv6 subnets.
+///
+/// Sealed so only `Ipv4Addr` and `Ipv6Addr` can implement it.
+pub trait Ip: sealed::Sealed + Copy + Eq + std::fmt::Debug + std::fmt::Display {
+    /// The unsigned integer backing arithmetic for this address family
+    /// (`u32` for IPv4, `u128` for IPv6).
+    type Int: Copy + Eq + Ord + std::fmt::Debug;
+
+    /// This address family's version.
+    const VERSION: IpVersion;
+
+    /// The address width in bits (32 for IPv4, 128 for IPv6).
+    const BITS: u32;
+
+    /// Converts the address to its backing integer.
+    ///
+    /// This method is marked with `#[allow(dead_code)]` to suppress warnings
+    /// about unused code, as it may not be used in all cases.
+    #[allow(dead_code)]
+    fn to_int(self) -> Self::Int;
+
+    /// Builds an address from its backing integer.
+    ///
+    /// This method is marked with `#[allow(dead_code)]` to suppress warnings
+    /// about unused code, as it may not be used in all cases.
+    #[allow(dead_code)]
+    fn from_int(value: Self::Int) -> Self;
+
+    /// Adds `rhs` to this address, clamping at the top of the address space.
+    fn saturating_add(self, rhs: Self::Int) -> Self;
+
+    /// Subtracts `rhs` from this address, clamping at the bottom of the
+    /// address space.
+    fn saturating_sub(self, rhs: Self::Int) -> Self;
+
+    /// The backing integer's zero value.
+    ///
+    /// This method is marked with `#[allow(dead_code)]` to suppress warnings
+    /// about unused code, as it may not be used in all cases.
+    #[allow(dead_code)]
+    fn int_zero() -> Self::Int;
+
+    /// The backing integer's value `1`.
+    fn int_one() -> Self::Int;
+
+    /// `a + b` on the backing integer, saturating at its maximum.
+    fn int_add(a: Self::Int, b: Self::Int) -> Self::Int;
+
+    /// `a - b` on the backing integer, saturating at zero.
+    fn int_sub(a: Self::Int, b: Self::Int) -> Self::Int;
+
+    /// `value << shift` on the backing integer.
+    fn int_shl(value: Self::Int, shift: u32) -> Self::Int;
+
+    /// The smallest power of two greater than or equal to `value`.
+    fn int_next_power_of_two(value: Self::Int) -> Self::Int;
+
+    /// The number of trailing zero bits in `value`.
+    fn int_trailing_zeros(value: Self::Int) -> u32;
+}
+
+impl Ip for crate::ipv4::Ipv4Addr {
+    type Int = u32;
+
+    const VERSION: IpVersion = IpVersion::V4;
+    const BITS: u32 = 32;
+
+    #[allow(dead_code)]
+    fn to_int(self) -> u32 {
+        self.to_u32()
+    }
+
+    #[allow(dead_code)]
+    fn from_int(value: u32) -> Self {
+        Self::from_u32(value)
+    }
+
+    fn saturating_add(self, rhs: u32) -> Self {
+        crate::ipv4::Ipv4Addr::saturating_add(&self, rhs)
+    }
+
+    fn saturating_sub(self, rhs: u32) -> Self {
+        crate::ipv4::Ipv4Addr::saturating_sub(&self, rhs)
+    }
+
+    #[allow(dead_code)]
+    fn int_zero() -> u32 {
+        0
+    }
+
+    fn int_one() -> u32 {
+        1
+    }
+
+    fn int_add(a: u32, b: u32) -> u32 {
+        a.saturating_add(b)
+    }
+
+    fn int_sub(a: u32, b: u32) -> u32 {
+        a.saturating_sub(b)
+    }
+
+    fn int_shl(value: u32, shift: u32) -> u32 {
+        value << shift
+    }
+
+    fn int_next_power_of_two(value: u32) -> u32 {
+        value.next_power_of_two()
+    }
+
+    fn int_trailing_zeros(value: u32) -> u32 {
+        value.trailing_zeros()
+    }
+}
+
+impl Ip for crate::ipv6::Ipv6Addr {
+    type Int = u128;
+
+    const VERSION: IpVersion = IpVersion::V6;
+    const BITS: u32 = 128;
+
+    #[allow(dead_code)]
+    fn to_int(self) -> u128 {
+        self.to_u128()
+    }
+
+    #[allow(dead_code)]
+    fn from_int(value: u128) -> Self {
+        Self::from_u128(value)
+    }
+
+    fn saturating_add(self, rhs: u128) -> Self {
+        crate::ipv6::Ipv6Addr::saturating_add(&self, rhs)
+    }
+
+    fn saturating_sub(self, rhs: u128) -> Self {
+        crate::ipv6::Ipv6Addr::saturating_sub(&self, rhs)
+    }
+
+    #[allow(dead_code)]
+    fn int_zero() -> u128 {
+        0
+    }
+
+    fn int_one() -> u128 {
+        1
+    }
+
+    fn int_add(a: u128, b: u128) -> u128 {
+        a.saturating_add(b)
+    }
+
+    fn int_sub(a: u128, b: u128) -> u128 {
+        a.saturating_sub(b)
+    }
+
+    fn int_shl(value: u128, shift: u32) -> u128 {
+        value << shift
+    }
+
+    fn int_next_power_of_two(value: u128) -> u128 {
+        value.next_power_of_two()
+    }
+
+    fn int_trailing_zeros(value: u128) -> u32 {
+        value.trailing_zeros()
+    }
+}
@@ -68,6 +68,34 @@ impl Ipv4Addr {
             | ((self.octets[2] as u32) << 8)
             | (self.octets[3] as u32)
     }
+
+    /// Adds `rhs` to this address, clamping at `255.255.255.255` instead of
+    /// overflowing.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The amount to add.
+    ///
+    /// # Returns
+    ///
+    /// The resulting `Ipv4Addr`, saturated at the top of the address space.
+    pub fn saturating_add(&self, rhs: u32) -> Self {
+        Self::from_u32(self.to_u32().saturating_add(rhs))
+    }
+
+    /// Subtracts `rhs` from this address, clamping at `0.0.0.0` instead of
+    /// underflowing.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The amount to subtract.
+    ///
+    /// # Returns
+    ///
+    /// The resulting `Ipv4Addr`, saturated at the bottom of the address space.
+    pub fn saturating_sub(&self, rhs: u32) -> Self {
+        Self::from_u32(self.to_u32().saturating_sub(rhs))
+    }
 }
 
 /// Implements the `Display` trait for `Ipv4Addr` to provide a custom string representation.
@@ -113,3 +141,117 @@ impl FromStr for Ipv4Addr {
         Ok(Ipv4Addr { octets: parsed })
     }
 }
+
+/// An inclusive iterator over every `Ipv4Addr` between two endpoints.
+///
+/// `Ipv4AddrRange` yields addresses in ascending order from `start` to `end`
+/// via `Iterator`, and in descending order from the other end via
+/// `DoubleEndedIterator`. It is `FusedIterator` since it keeps returning
+/// `None` once exhausted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ipv4AddrRange {
+    next: u32,
+    next_back: u32,
+    done: bool,
+}
+
+impl Ipv4AddrRange {
+    /// Creates a range that yields every address from `start` to `end`,
+    /// inclusive of both endpoints.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The first address to yield.
+    /// * `end` - The last address to yield.
+    ///
+    /// # Returns
+    ///
+    /// An `Ipv4AddrRange`. If `start` is greater than `end` the range is
+    /// empty.
+    pub fn new(start: Ipv4Addr, end: Ipv4Addr) -> Self {
+        let next = start.to_u32();
+        let next_back = end.to_u32();
+        Self {
+            next,
+            next_back,
+            done: next > next_back,
+        }
+    }
+}
+
+impl Iterator for Ipv4AddrRange {
+    type Item = Ipv4Addr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let current = self.next;
+        if current == self.next_back {
+            self.done = true;
+        } else {
+            self.next += 1;
+        }
+        Some(Ipv4Addr::from_u32(current))
+    }
+}
+
+impl DoubleEndedIterator for Ipv4AddrRange {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let current = self.next_back;
+        if current == self.next {
+            self.done = true;
+        } else {
+            self.next_back -= 1;
+        }
+        Some(Ipv4Addr::from_u32(current))
+    }
+}
+
+impl std::iter::FusedIterator for Ipv4AddrRange {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ipv4_addr_range_is_inclusive_and_double_ended() {
+        let addrs: Vec<Ipv4Addr> =
+            Ipv4AddrRange::new(Ipv4Addr::new(192, 168, 0, 1), Ipv4Addr::new(192, 168, 0, 3))
+                .collect();
+        assert_eq!(
+            addrs,
+            vec![
+                Ipv4Addr::new(192, 168, 0, 1),
+                Ipv4Addr::new(192, 168, 0, 2),
+                Ipv4Addr::new(192, 168, 0, 3),
+            ]
+        );
+
+        let rev: Vec<Ipv4Addr> =
+            Ipv4AddrRange::new(Ipv4Addr::new(192, 168, 0, 1), Ipv4Addr::new(192, 168, 0, 3))
+                .rev()
+                .collect();
+        assert_eq!(
+            rev,
+            vec![
+                Ipv4Addr::new(192, 168, 0, 3),
+                Ipv4Addr::new(192, 168, 0, 2),
+                Ipv4Addr::new(192, 168, 0, 1),
+            ]
+        );
+
+        let single: Vec<Ipv4Addr> =
+            Ipv4AddrRange::new(Ipv4Addr::new(10, 0, 0, 5), Ipv4Addr::new(10, 0, 0, 5)).collect();
+        assert_eq!(single, vec![Ipv4Addr::new(10, 0, 0, 5)]);
+
+        let empty: Vec<Ipv4Addr> =
+            Ipv4AddrRange::new(Ipv4Addr::new(10, 0, 0, 5), Ipv4Addr::new(10, 0, 0, 4)).collect();
+        assert!(empty.is_empty());
+    }
+}
@@ -0,0 +1,198 @@
+/// Represents an IPv6 address.
+///
+/// The `Ipv6Addr` struct stores an IPv6 address as an array of eight
+/// 16-bit hextets, mirroring how `Ipv4Addr` stores four octets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ipv6Addr {
+    /// The eight hextets of the IPv6 address.
+    /// Example: [0x2001, 0x0db8, 0, 0, 0, 0, 0, 1]
+    pub hextets: [u16; 8],
+}
+
+impl Ipv6Addr {
+    /// Creates a new `Ipv6Addr` from eight hextets.
+    ///
+    /// This method is marked with `#[allow(dead_code)]` to suppress warnings
+    /// about unused code, as it may not be used in all cases.
+    #[allow(dead_code)]
+    pub fn new(hextets: [u16; 8]) -> Self {
+        Self { hextets }
+    }
+
+    /// Creates a new `Ipv6Addr` from a `u128` value.
+    ///
+    /// The `u128` value is expected to be in network byte order.
+    pub fn from_u128(ip: u128) -> Self {
+        let mut hextets = [0u16; 8];
+        for (i, hextet) in hextets.iter_mut().enumerate() {
+            let shift = (7 - i) * 16;
+            *hextet = ((ip >> shift) & 0xffff) as u16;
+        }
+        Self { hextets }
+    }
+
+    /// Converts the `Ipv6Addr` to a `u128` value.
+    ///
+    /// The resulting `u128` value is in network byte order.
+    pub fn to_u128(&self) -> u128 {
+        self.hextets
+            .iter()
+            .fold(0u128, |acc, &hextet| (acc << 16) | hextet as u128)
+    }
+
+    /// Adds `rhs` to this address, clamping at `ffff:...:ffff` instead of
+    /// overflowing.
+    pub fn saturating_add(&self, rhs: u128) -> Self {
+        Self::from_u128(self.to_u128().saturating_add(rhs))
+    }
+
+    /// Subtracts `rhs` from this address, clamping at `::` instead of
+    /// underflowing.
+    pub fn saturating_sub(&self, rhs: u128) -> Self {
+        Self::from_u128(self.to_u128().saturating_sub(rhs))
+    }
+
+    /// Finds the longest run of zero hextets, for `::` compression in `Display`.
+    ///
+    /// # Returns
+    ///
+    /// The `(start, len)` of the longest run. `len` is `0` if there is no
+    /// run of two or more zero hextets.
+    fn longest_zero_run(&self) -> (usize, usize) {
+        let mut best = (0, 0);
+        let mut current_start = 0;
+        let mut current_len = 0;
+
+        for (i, &hextet) in self.hextets.iter().enumerate() {
+            if hextet == 0 {
+                if current_len == 0 {
+                    current_start = i;
+                }
+                current_len += 1;
+                if current_len > best.1 {
+                    best = (current_start, current_len);
+                }
+            } else {
+                current_len = 0;
+            }
+        }
+
+        if best.1 > 1 {
+            best
+        } else {
+            (0, 0)
+        }
+    }
+}
+
+/// Implements the `Display` trait for `Ipv6Addr`, printing the canonical
+/// hextet form with the longest run of zero hextets compressed to `::`.
+impl std::fmt::Display for Ipv6Addr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (run_start, run_len) = self.longest_zero_run();
+
+        if run_len == 0 {
+            let hextets: Vec<String> = self.hextets.iter().map(|h| format!("{:x}", h)).collect();
+            return write!(f, "{}", hextets.join(":"));
+        }
+
+        let before: Vec<String> = self.hextets[..run_start]
+            .iter()
+            .map(|h| format!("{:x}", h))
+            .collect();
+        let after: Vec<String> = self.hextets[run_start + run_len..]
+            .iter()
+            .map(|h| format!("{:x}", h))
+            .collect();
+
+        write!(f, "{}::{}", before.join(":"), after.join(":"))
+    }
+}
+
+/// Implements the `FromStr` trait for `Ipv6Addr` to allow parsing from a
+/// string, including the `"::"` zero-compression form.
+///
+/// If the string is not a valid IPv6 address, an error is returned.
+use std::str::FromStr;
+
+impl FromStr for Ipv6Addr {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid IPv6 address: {}", s);
+
+        if let Some((head, tail)) = s.split_once("::") {
+            let head_parts = if head.is_empty() {
+                Vec::new()
+            } else {
+                head.split(':').collect::<Vec<_>>()
+            };
+            let tail_parts = if tail.is_empty() {
+                Vec::new()
+            } else {
+                tail.split(':').collect::<Vec<_>>()
+            };
+
+            if head_parts.len() + tail_parts.len() >= 8 {
+                return Err(invalid());
+            }
+
+            let mut hextets = [0u16; 8];
+            for (i, part) in head_parts.iter().enumerate() {
+                hextets[i] = u16::from_str_radix(part, 16).map_err(|_| invalid())?;
+            }
+            let tail_start = 8 - tail_parts.len();
+            for (i, part) in tail_parts.iter().enumerate() {
+                hextets[tail_start + i] = u16::from_str_radix(part, 16).map_err(|_| invalid())?;
+            }
+
+            Ok(Self { hextets })
+        } else {
+            let parts: Vec<&str> = s.split(':').collect();
+            if parts.len() != 8 {
+                return Err(invalid());
+            }
+
+            let mut hextets = [0u16; 8];
+            for (i, part) in parts.iter().enumerate() {
+                hextets[i] = u16::from_str_radix(part, 16).map_err(|_| invalid())?;
+            }
+
+            Ok(Self { hextets })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trips_through_u128() {
+        let addr = Ipv6Addr::new([0x2001, 0x0db8, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(Ipv6Addr::from_u128(addr.to_u128()), addr);
+    }
+
+    #[test]
+    fn test_parses_and_displays_compressed_form() {
+        let addr = Ipv6Addr::from_str("2001:db8::1").unwrap();
+        assert_eq!(addr, Ipv6Addr::new([0x2001, 0x0db8, 0, 0, 0, 0, 0, 1]));
+        assert_eq!(addr.to_string(), "2001:db8::1");
+
+        let loopback = Ipv6Addr::from_str("::1").unwrap();
+        assert_eq!(loopback, Ipv6Addr::new([0, 0, 0, 0, 0, 0, 0, 1]));
+        assert_eq!(loopback.to_string(), "::1");
+
+        let unspecified = Ipv6Addr::from_str("::").unwrap();
+        assert_eq!(unspecified, Ipv6Addr::new([0; 8]));
+        assert_eq!(unspecified.to_string(), "::");
+
+        assert!(Ipv6Addr::from_str("not-an-address").is_err());
+    }
+
+    #[test]
+    fn test_parses_full_form() {
+        let addr = Ipv6Addr::from_str("2001:db8:0:0:0:0:0:1").unwrap();
+        assert_eq!(addr, Ipv6Addr::new([0x2001, 0x0db8, 0, 0, 0, 0, 0, 1]));
+    }
+}
@@ -1,23 +1,97 @@
 use std::str::FromStr;
 
 use crate::ipv4::Ipv4Addr;
+use crate::ipv6::Ipv6Addr;
 use clipboard_rs::{Clipboard, ClipboardContext};
 use iced::widget::{scrollable, Button, Column, Container, Row, Text, TextInput};
 use iced::{color, Application, Command, Element, Length, Settings, Theme};
 
-use crate::subnet::{Calculator, Subnet};
+use crate::network::Ipv4Network;
+use crate::subnet::{AllocError, Calculator, Subnet};
 
+mod ip;
 mod ipv4;
+mod ipv6;
+mod network;
 mod subnet;
 
+/// Either an IPv4 or an IPv6 address, used to pick which `Calculator`
+/// instantiation to drive once the GUI has detected the input's family.
+#[derive(Debug, Clone, Copy)]
+enum AnyAddr {
+    V4(Ipv4Addr),
+    V6(Ipv6Addr),
+}
+
+/// Parses an address, detecting its family from the presence of a `:`.
+impl FromStr for AnyAddr {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.contains(':') {
+            Ipv6Addr::from_str(s).map(AnyAddr::V6)
+        } else {
+            Ipv4Addr::from_str(s).map(AnyAddr::V4)
+        }
+    }
+}
+
+/// Either an IPv4 or an IPv6 `Subnet`, so a single history list can hold
+/// results from both families.
+#[derive(Debug, Clone, Copy)]
+enum AnySubnet {
+    V4(Subnet<Ipv4Addr>),
+    V6(Subnet<Ipv6Addr>),
+}
+
+impl AnySubnet {
+    /// Formats this subnet as the same six lines for either address family;
+    /// the mask length is labelled `Mask` for IPv4 and `Prefix` for IPv6.
+    fn describe(&self) -> String {
+        match self {
+            AnySubnet::V4(subnet) => format!(
+                "Address: {}\nMask: {}\nStart: {}\nEnd: {}\nBroadcast: {}\nHosts: {}",
+                subnet.address,
+                subnet.mask_length,
+                subnet.start,
+                subnet.end,
+                subnet.broadcast,
+                subnet.hosts
+            ),
+            AnySubnet::V6(subnet) => format!(
+                "Address: {}\nPrefix: {}\nStart: {}\nEnd: {}\nBroadcast: {}\nHosts: {}",
+                subnet.address,
+                subnet.mask_length,
+                subnet.start,
+                subnet.end,
+                subnet.broadcast,
+                subnet.hosts
+            ),
+        }
+    }
+}
+
 struct SubnetCalculator {
     network_address: String,
     num_hosts: String,
-    last_address: Option<Ipv4Addr>,
-    result: Option<Subnet>,
+    last_address: Option<AnyAddr>,
+    result: Option<AnySubnet>,
     error_message: String,
-    history: Vec<Subnet>,
+    history: Vec<AnySubnet>,
     clipboard: ClipboardContext,
+    show_hosts: bool,
+    cidr_input: String,
+    cidr_contains_input: String,
+    cidr_info: Option<Ipv4Network>,
+    cidr_error: String,
+    cidr_contains_result: Option<bool>,
+    exclude_input: String,
+    exclude_result: Vec<Ipv4Network>,
+    exclude_error: String,
+    vlsm_requirements_input: String,
+    vlsm_result: Vec<Subnet<Ipv4Addr>>,
+    vlsm_remaining: Option<u32>,
+    vlsm_error: String,
 }
 
 #[derive(Debug, Clone)]
@@ -27,6 +101,24 @@ enum Message {
     Calculate,
     Reset,
     CopyHistory(usize),
+    ToggleHostList,
+    CidrInputChanged(String),
+    CidrContainsInputChanged(String),
+    AnalyzeCidr,
+    ExcludeInputChanged(String),
+    Subtract,
+    VlsmRequirementsInputChanged(String),
+    AllocateVlsm,
+}
+
+impl SubnetCalculator {
+    /// Re-checks `cidr_contains_input` against `cidr_info`, if both are set.
+    fn update_cidr_contains_result(&mut self) {
+        self.cidr_contains_result = match (&self.cidr_info, Ipv4Addr::from_str(&self.cidr_contains_input)) {
+            (Some(network), Ok(addr)) => Some(network.contains(&addr)),
+            _ => None,
+        };
+    }
 }
 
 impl Application for SubnetCalculator {
@@ -49,25 +141,37 @@ impl Application for SubnetCalculator {
                 error_message: String::new(),
                 history: Vec::new(),
                 clipboard: ClipboardContext::new().unwrap(),
+                show_hosts: false,
+                cidr_input: String::new(),
+                cidr_contains_input: String::new(),
+                cidr_info: None,
+                cidr_error: String::new(),
+                cidr_contains_result: None,
+                exclude_input: String::new(),
+                exclude_result: Vec::new(),
+                exclude_error: String::new(),
+                vlsm_requirements_input: String::new(),
+                vlsm_result: Vec::new(),
+                vlsm_remaining: None,
+                vlsm_error: String::new(),
             },
             Command::none(),
         )
     }
 
     fn title(&self) -> String {
-        String::from("IPv4 Subnet Calculator")
+        String::from("IP Subnet Calculator")
     }
 
     fn update(&mut self, message: Message) -> Command<Message> {
         match message {
             Message::NetworkAddressChanged(network_address) => {
                 self.network_address = network_address;
-                self.last_address = Ipv4Addr::from_str(&self.network_address).ok();
+                self.last_address = AnyAddr::from_str(&self.network_address).ok();
             }
             Message::NumHostsChanged(num_hosts) => self.num_hosts = num_hosts,
             Message::Calculate => {
                 self.error_message.clear();
-                let num_hosts = self.num_hosts.parse().unwrap_or(0);
                 let last_address = match self.last_address {
                     Some(addr) => addr,
                     None => {
@@ -75,14 +179,33 @@ impl Application for SubnetCalculator {
                         return Command::none();
                     }
                 };
-                if num_hosts == 0 {
-                    self.error_message = "Invalid number of hosts".to_string();
-                    return Command::none();
-                }
-                let result = Calculator::generate_subnet(last_address, num_hosts);
-                self.result = Some(result.0);
-                self.last_address = Some(result.1);
-                self.history.push(result.0);
+
+                let subnet = match last_address {
+                    AnyAddr::V4(addr) => {
+                        let num_hosts = self.num_hosts.parse().unwrap_or(0);
+                        if num_hosts == 0 {
+                            self.error_message = "Invalid number of hosts".to_string();
+                            return Command::none();
+                        }
+                        let (subnet, next_address) = Calculator::generate_subnet(addr, num_hosts);
+                        self.last_address = Some(AnyAddr::V4(next_address));
+                        AnySubnet::V4(subnet)
+                    }
+                    AnyAddr::V6(addr) => {
+                        let num_hosts = self.num_hosts.parse().unwrap_or(0);
+                        if num_hosts == 0 {
+                            self.error_message = "Invalid number of hosts".to_string();
+                            return Command::none();
+                        }
+                        let (subnet, next_address) = Calculator::generate_subnet(addr, num_hosts);
+                        self.last_address = Some(AnyAddr::V6(next_address));
+                        AnySubnet::V6(subnet)
+                    }
+                };
+
+                self.result = Some(subnet);
+                self.history.push(subnet);
+                self.show_hosts = false;
             }
             Message::Reset => {
                 self.network_address.clear();
@@ -91,19 +214,108 @@ impl Application for SubnetCalculator {
                 self.result = None;
                 self.error_message.clear();
                 self.history.clear();
+                self.show_hosts = false;
+            }
+            Message::ToggleHostList => {
+                self.show_hosts = !self.show_hosts;
             }
             Message::CopyHistory(index) => {
                 if let Some(result) = self.history.get(index) {
-                    let result_text = format!(
-                        "Address: {}\nMask: {}\nStart: {}\nEnd: {}\nBroadcast: {}\nHosts: {}",
-                        result.address,
-                        result.mask_length,
-                        result.start,
-                        result.end,
-                        result.broadcast,
-                        result.hosts
-                    );
-                    self.clipboard.set_text(result_text).unwrap();
+                    self.clipboard.set_text(result.describe()).unwrap();
+                }
+            }
+            Message::CidrInputChanged(cidr_input) => {
+                self.cidr_input = cidr_input;
+            }
+            Message::CidrContainsInputChanged(cidr_contains_input) => {
+                self.cidr_contains_input = cidr_contains_input;
+                self.update_cidr_contains_result();
+            }
+            Message::AnalyzeCidr => {
+                self.cidr_error.clear();
+                match Ipv4Network::from_str(&self.cidr_input) {
+                    Ok(network) => self.cidr_info = Some(network),
+                    Err(err) => {
+                        self.cidr_error = err;
+                        self.cidr_info = None;
+                    }
+                }
+                self.update_cidr_contains_result();
+            }
+            Message::ExcludeInputChanged(exclude_input) => {
+                self.exclude_input = exclude_input;
+            }
+            Message::Subtract => {
+                self.exclude_error.clear();
+                let parent = match &self.cidr_info {
+                    Some(network) => *network,
+                    None => {
+                        self.exclude_error = "Analyze a network above first".to_string();
+                        self.exclude_result.clear();
+                        return Command::none();
+                    }
+                };
+                match Ipv4Network::from_str(&self.exclude_input) {
+                    Ok(exclude) => self.exclude_result = parent.subtract(&exclude),
+                    Err(err) => {
+                        self.exclude_error = err;
+                        self.exclude_result.clear();
+                    }
+                }
+            }
+            Message::VlsmRequirementsInputChanged(vlsm_requirements_input) => {
+                self.vlsm_requirements_input = vlsm_requirements_input;
+            }
+            Message::AllocateVlsm => {
+                self.vlsm_error.clear();
+                let parent = match &self.cidr_info {
+                    Some(network) => *network,
+                    None => {
+                        self.vlsm_error = "Analyze a network above first".to_string();
+                        self.vlsm_result.clear();
+                        self.vlsm_remaining = None;
+                        return Command::none();
+                    }
+                };
+
+                let requirements: Result<Vec<u32>, String> = self
+                    .vlsm_requirements_input
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|part| !part.is_empty())
+                    .map(|part| {
+                        part.parse::<u32>()
+                            .map_err(|_| format!("Invalid host count: {}", part))
+                    })
+                    .collect();
+
+                let requirements = match requirements {
+                    Ok(requirements) if !requirements.is_empty() => requirements,
+                    Ok(_) => {
+                        self.vlsm_error = "Enter at least one host count".to_string();
+                        self.vlsm_result.clear();
+                        self.vlsm_remaining = None;
+                        return Command::none();
+                    }
+                    Err(err) => {
+                        self.vlsm_error = err;
+                        self.vlsm_result.clear();
+                        self.vlsm_remaining = None;
+                        return Command::none();
+                    }
+                };
+
+                match Calculator::allocate_vlsm(parent, &requirements) {
+                    Ok(allocated) => {
+                        self.vlsm_remaining =
+                            Some(Calculator::remaining_capacity(parent, &allocated));
+                        self.vlsm_result = allocated;
+                    }
+                    Err(AllocError::ExceedsParent) => {
+                        self.vlsm_error = AllocError::ExceedsParent.to_string();
+                        self.vlsm_result.clear();
+                        self.vlsm_remaining = None;
+                    }
                 }
             }
         }
@@ -111,11 +323,11 @@ impl Application for SubnetCalculator {
     }
 
     fn view(&self) -> Element<Message> {
-        let title = Text::new("IPv4 Subnet Calculator")
+        let title = Text::new("IP Subnet Calculator")
             .size(30)
             .width(Length::Fill);
 
-        let network_address_input = TextInput::new("Network Address", &self.network_address)
+        let network_address_input = TextInput::new("Network Address (IPv4 or IPv6)", &self.network_address)
             .on_input(Message::NetworkAddressChanged)
             .padding(10);
 
@@ -133,19 +345,36 @@ impl Application for SubnetCalculator {
             .spacing(10);
 
         let result_text = if let Some(result) = &self.result {
-            Column::new().push(
-                Text::new(format!(
-                    "Address: {}\nMask: {}\nStart: {}\nEnd: {}\nBroadcast: {}\nHosts: {}",
-                    result.address,
-                    result.mask_length,
-                    result.start,
-                    result.end,
-                    result.broadcast,
-                    result.hosts
-                ))
-                .size(20)
-                .width(Length::Fill),
-            )
+            let mut column = Column::new()
+                .push(Text::new(result.describe()).size(20).width(Length::Fill))
+                .spacing(10);
+
+            // Listing individual host addresses only makes sense for the
+            // bounded IPv4 ranges; an IPv6 /64 would iterate forever.
+            if let AnySubnet::V4(subnet) = result {
+                column = column.push(
+                    Button::new(if self.show_hosts {
+                        "Hide Host Addresses"
+                    } else {
+                        "List Host Addresses"
+                    })
+                    .on_press(Message::ToggleHostList)
+                    .padding(10),
+                );
+
+                if self.show_hosts {
+                    let host_list = Calculator::hosts(subnet)
+                        .map(|host| Text::new(host.to_string()).size(16).into())
+                        .collect::<Vec<_>>();
+                    column = column.push(
+                        scrollable(Column::with_children(host_list))
+                            .height(Length::Fixed(150.0))
+                            .width(Length::Fill),
+                    );
+                }
+            }
+
+            column
         } else {
             Column::new().push(
                 Text::new(&self.error_message)
@@ -164,13 +393,9 @@ impl Application for SubnetCalculator {
                         .map(|(index, result)| {
                             Row::new()
                                 .push(
-                                    Text::new(format!(
-                                        "Address: {}\nMask: {}\nStart: {}\nEnd: {}\nBroadcast: {}\nHosts: {}",
-                                        result.address, result.mask_length, result.start, result.end,
-                                        result.broadcast, result.hosts
-                                    ))
-                                    .width(Length::Fill)
-                                    .size(16),
+                                    Text::new(result.describe())
+                                        .width(Length::Fill)
+                                        .size(16),
                                 )
                                 .push(
                                     Button::new("Copy")
@@ -188,6 +413,120 @@ impl Application for SubnetCalculator {
             )
             .padding(10);
 
+        let cidr_section = {
+            let cidr_input = TextInput::new("Network (CIDR, e.g. 192.168.1.0/24)", &self.cidr_input)
+                .on_input(Message::CidrInputChanged)
+                .padding(10);
+
+            let cidr_contains_input = TextInput::new(
+                "Check address is in network",
+                &self.cidr_contains_input,
+            )
+            .on_input(Message::CidrContainsInputChanged)
+            .padding(10);
+
+            let analyze_button = Button::new("Analyze")
+                .on_press(Message::AnalyzeCidr)
+                .padding(10);
+
+            let cidr_output = if let Some(network) = &self.cidr_info {
+                let mut text = format!(
+                    "Network: {}\nNetmask: {}\nBroadcast: {}\nPrefix: /{}",
+                    network.network_address(),
+                    network.netmask(),
+                    network.broadcast(),
+                    network.prefix()
+                );
+                if let Some(contains) = self.cidr_contains_result {
+                    text.push_str(&format!(
+                        "\nContains {}: {}",
+                        self.cidr_contains_input, contains
+                    ));
+                }
+                Text::new(text).size(16)
+            } else {
+                Text::new(&self.cidr_error).size(16).style(color!(0xff0000))
+            };
+
+            Column::new()
+                .push(Text::new("Network Info (CIDR)").size(24))
+                .push(cidr_input)
+                .push(cidr_contains_input)
+                .push(analyze_button)
+                .push(cidr_output)
+                .spacing(10)
+        };
+
+        let exclude_section = {
+            let exclude_input = TextInput::new(
+                "Exclude (CIDR, e.g. 192.168.1.64/26)",
+                &self.exclude_input,
+            )
+            .on_input(Message::ExcludeInputChanged)
+            .padding(10);
+
+            let subtract_button = Button::new("Subtract").on_press(Message::Subtract).padding(10);
+
+            let exclude_output = if !self.exclude_error.is_empty() {
+                Text::new(&self.exclude_error).size(16).style(color!(0xff0000))
+            } else if self.exclude_result.is_empty() {
+                Text::new("No remaining blocks").size(16)
+            } else {
+                Text::new(
+                    self.exclude_result
+                        .iter()
+                        .map(|network| network.to_string())
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                )
+                .size(16)
+            };
+
+            Column::new()
+                .push(Text::new("Exclude a Sub-Block (from Network Info above)").size(24))
+                .push(exclude_input)
+                .push(subtract_button)
+                .push(exclude_output)
+                .spacing(10)
+        };
+
+        let vlsm_section = {
+            let requirements_input = TextInput::new(
+                "Host counts, comma-separated (e.g. 50, 20, 5)",
+                &self.vlsm_requirements_input,
+            )
+            .on_input(Message::VlsmRequirementsInputChanged)
+            .padding(10);
+
+            let allocate_button = Button::new("Allocate VLSM")
+                .on_press(Message::AllocateVlsm)
+                .padding(10);
+
+            let vlsm_output = if !self.vlsm_error.is_empty() {
+                Text::new(&self.vlsm_error).size(16).style(color!(0xff0000))
+            } else if self.vlsm_result.is_empty() {
+                Text::new("No subnets allocated yet").size(16)
+            } else {
+                let mut text = self
+                    .vlsm_result
+                    .iter()
+                    .map(|subnet| format!("{}/{} ({} hosts)", subnet.address, subnet.mask_length, subnet.hosts))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                if let Some(remaining) = self.vlsm_remaining {
+                    text.push_str(&format!("\nRemaining capacity: {} addresses", remaining));
+                }
+                Text::new(text).size(16)
+            };
+
+            Column::new()
+                .push(Text::new("VLSM Allocator (from Network Info above)").size(24))
+                .push(requirements_input)
+                .push(allocate_button)
+                .push(vlsm_output)
+                .spacing(10)
+        };
+
         let content = Column::new()
             .push(title)
             .push(network_address_input)
@@ -195,6 +534,9 @@ impl Application for SubnetCalculator {
             .push(button_row)
             .push(result_text)
             .push(history_text)
+            .push(cidr_section)
+            .push(exclude_section)
+            .push(vlsm_section)
             .spacing(20)
             .padding(20)
             .width(Length::Fill)
@@ -0,0 +1,224 @@
+use std::str::FromStr;
+
+use crate::ipv4::Ipv4Addr;
+
+/// Represents an IPv4 network in CIDR notation, e.g. `192.168.1.0/24`.
+///
+/// An `Ipv4Network` pairs a network address with a prefix length and
+/// guarantees that no host bits are set in the network address, so the
+/// calculator and GUI can work with a single validated CIDR value instead
+/// of a separate address and host count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ipv4Network {
+    network_address: Ipv4Addr,
+    prefix: u8,
+}
+
+impl Ipv4Network {
+    /// Creates a new `Ipv4Network` from a network address and prefix length.
+    ///
+    /// # Arguments
+    ///
+    /// * `network_address` - The network (base) address of the CIDR block.
+    /// * `prefix` - The prefix length, from 0 to 32.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(Ipv4Network)` if `prefix` is in range and `network_address` has
+    /// no host bits set, otherwise `Err` describing the problem.
+    pub fn new(network_address: Ipv4Addr, prefix: u8) -> Result<Self, String> {
+        if prefix > 32 {
+            return Err(format!("Invalid prefix length: {}", prefix));
+        }
+
+        let mask = Self::mask_for_prefix(prefix);
+        if network_address.to_u32() & !mask != 0 {
+            return Err(format!(
+                "Network address {} has host bits set for prefix /{}",
+                network_address, prefix
+            ));
+        }
+
+        Ok(Self {
+            network_address,
+            prefix,
+        })
+    }
+
+    /// Computes the subnet mask, as a `u32`, for the given prefix length.
+    fn mask_for_prefix(prefix: u8) -> u32 {
+        if prefix == 0 {
+            0
+        } else {
+            u32::MAX << (32 - prefix)
+        }
+    }
+
+    /// Returns the network (base) address of this CIDR block.
+    pub fn network_address(&self) -> Ipv4Addr {
+        self.network_address
+    }
+
+    /// Returns the prefix length of this CIDR block.
+    pub fn prefix(&self) -> u8 {
+        self.prefix
+    }
+
+    /// Returns the subnet mask of this CIDR block as an `Ipv4Addr`.
+    pub fn netmask(&self) -> Ipv4Addr {
+        Ipv4Addr::from_u32(Self::mask_for_prefix(self.prefix))
+    }
+
+    /// Returns the broadcast address of this CIDR block.
+    pub fn broadcast(&self) -> Ipv4Addr {
+        let mask = Self::mask_for_prefix(self.prefix);
+        Ipv4Addr::from_u32(self.network_address.to_u32() | !mask)
+    }
+
+    /// Returns `true` if `addr` falls within this network.
+    pub fn contains(&self, addr: &Ipv4Addr) -> bool {
+        let mask = Self::mask_for_prefix(self.prefix);
+        (addr.to_u32() & mask) == (self.network_address.to_u32() & mask)
+    }
+
+    /// Excludes `other` from this network, returning the minimal set of
+    /// CIDR blocks that cover `self \ other`.
+    ///
+    /// `other` is expected to be fully contained in `self` (a longer,
+    /// more specific prefix). If the two networks are disjoint, `self` is
+    /// returned unchanged; if `other` equals or contains `self`, the result
+    /// is empty.
+    ///
+    /// The algorithm walks from `other`'s prefix up to `self`'s: at each
+    /// bit position it peels off the sibling block not containing `other`
+    /// and keeps ascending, so the returned blocks double in size as they
+    /// get closer to `self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The more specific network to carve out of `self`.
+    ///
+    /// # Returns
+    ///
+    /// The CIDR blocks covering `self` minus `other`.
+    pub fn subtract(&self, other: &Ipv4Network) -> Vec<Ipv4Network> {
+        if other.prefix <= self.prefix && other.contains(&self.network_address) {
+            return Vec::new();
+        }
+
+        if other.prefix <= self.prefix || !self.contains(&other.network_address) {
+            return vec![*self];
+        }
+
+        let lo = 32 - other.prefix as u32;
+        let hi = 32 - self.prefix as u32;
+        let mut current = other.network_address.to_u32();
+
+        let mut blocks = Vec::with_capacity((hi - lo) as usize);
+        for i in lo..hi {
+            let bit = 1u32 << i;
+            let sibling_address = Ipv4Addr::from_u32(current ^ bit);
+            let sibling_prefix = (32 - i) as u8;
+            blocks.push(
+                Ipv4Network::new(sibling_address, sibling_prefix)
+                    .expect("sibling block is always aligned to its own prefix"),
+            );
+            current &= !bit;
+        }
+
+        blocks
+    }
+}
+
+/// Implements the `Display` trait for `Ipv4Network`, printing it in CIDR
+/// notation, e.g. `192.168.1.0/24`.
+impl std::fmt::Display for Ipv4Network {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.network_address, self.prefix)
+    }
+}
+
+/// Implements the `FromStr` trait for `Ipv4Network` to allow parsing from
+/// CIDR notation, e.g. `"192.168.1.0/24"`.
+impl FromStr for Ipv4Network {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(2, '/');
+        let addr_part = parts
+            .next()
+            .ok_or_else(|| format!("Invalid CIDR network: {}", s))?;
+        let prefix_part = parts
+            .next()
+            .ok_or_else(|| format!("Invalid CIDR network: {}", s))?;
+
+        let network_address = Ipv4Addr::from_str(addr_part)?;
+        let prefix = prefix_part
+            .parse::<u8>()
+            .map_err(|_| format!("Invalid prefix length: {}", prefix_part))?;
+
+        Self::new(network_address, prefix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_rejects_invalid_prefix_and_host_bits() {
+        // Valid network.
+        let network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap();
+        assert_eq!(network.network_address(), Ipv4Addr::new(192, 168, 1, 0));
+        assert_eq!(network.prefix(), 24);
+
+        // Prefix out of range.
+        assert!(Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 33).is_err());
+
+        // Host bits set in the network address.
+        assert!(Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 1), 24).is_err());
+    }
+
+    #[test]
+    fn test_parsing_and_accessors() {
+        let network = Ipv4Network::from_str("192.168.1.0/24").unwrap();
+        assert_eq!(network.network_address(), Ipv4Addr::new(192, 168, 1, 0));
+        assert_eq!(network.prefix(), 24);
+        assert_eq!(network.netmask(), Ipv4Addr::new(255, 255, 255, 0));
+        assert_eq!(network.broadcast(), Ipv4Addr::new(192, 168, 1, 255));
+        assert_eq!(network.to_string(), "192.168.1.0/24");
+
+        assert!(network.contains(&Ipv4Addr::new(192, 168, 1, 42)));
+        assert!(!network.contains(&Ipv4Addr::new(192, 168, 2, 1)));
+
+        assert!(Ipv4Network::from_str("192.168.1.1/24").is_err());
+        assert!(Ipv4Network::from_str("not-an-address").is_err());
+    }
+
+    #[test]
+    fn test_subtract_carves_out_sub_block() {
+        let parent = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap();
+        let excluded = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 64), 26).unwrap();
+
+        let remaining = parent.subtract(&excluded);
+        assert_eq!(
+            remaining,
+            vec![
+                Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 26).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 128), 25).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_subtract_edge_cases() {
+        let parent = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap();
+
+        // Subtracting itself leaves nothing.
+        assert!(parent.subtract(&parent).is_empty());
+
+        // A disjoint network leaves the parent unchanged.
+        let disjoint = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 24).unwrap();
+        assert_eq!(parent.subtract(&disjoint), vec![parent]);
+    }
+}
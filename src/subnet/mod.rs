@@ -1,28 +1,34 @@
-use crate::ipv4::Ipv4Addr;
+use std::marker::PhantomData;
 
-/// IPv4 Subnet Calculator
+use crate::ip::{Ip, IpVersion};
+use crate::ipv4::{Ipv4Addr, Ipv4AddrRange};
+use crate::network::Ipv4Network;
+
+/// IP Subnet Calculator
 ///
 /// This calculator generates subnets based on a given network address and the number of required hosts.
+/// It is generic over the `Ip` trait, so the same logic drives both IPv4 and IPv6 subnetting.
 ///
 /// The subnet calculation process involves the following steps:
 ///
 /// 1. Determine the number of bits needed for the subnet mask:
 ///    - Find the next power of two greater than or equal to the number of required hosts.
-///    - Subtract the position of the most significant bit of that power of two from 32.
+///    - Subtract the position of the most significant bit of that power of two from the address width.
 ///    - The result is the number of bits needed for the subnet mask.
 ///
 /// 2. Calculate the address increment:
-///    - Left-shift 1 by (32 - subnet mask length) bits.
+///    - Left-shift 1 by (address width - subnet mask length) bits.
 ///    - This gives the increment between consecutive subnets.
 ///
 /// 3. Generate the subnet:
-///    - The network address is the last address of the previous subnet.
-///    - The usable range starts at (network address + 1).
-///    - The broadcast address is (network address + address increment - 1).
-///    - The usable range ends at (broadcast address - 1).
-///    - The number of usable hosts is (address increment - 2).
+///    - For IPv4, the network and broadcast addresses are reserved: the usable range is
+///      (network address + 1) to (broadcast address - 1), and usable hosts is (increment - 2).
+///      `/31` subnets are point-to-point links (both addresses usable) and `/32` subnets are a
+///      single host, matching RFC 3021 instead of the general convention.
+///    - For IPv6, there is no broadcast convention: the usable range is the whole block, network
+///      address to broadcast address inclusive, and usable hosts is the increment itself.
 ///
-/// Example:
+/// Example (IPv4):
 ///
 /// For a network address of 192.168.0.0 and 50 required hosts:
 /// - The next power of two greater than or equal to 50 is 64.
@@ -35,20 +41,39 @@ use crate::ipv4::Ipv4Addr;
 ///
 /// The calculator generates subnets sequentially based on the last address of the previous subnet
 /// and the number of required hosts for each subnet.
-pub struct Calculator;
+pub struct Calculator<T: Ip>(PhantomData<T>);
 
 /// Represents a subnet with its address, mask length, usable range, broadcast address, and number of hosts.
 #[derive(Debug, PartialEq, Clone, Copy)]
-pub struct Subnet {
-    pub address: Ipv4Addr,
+pub struct Subnet<T: Ip> {
+    pub address: T,
     pub mask_length: u32,
-    pub start: Ipv4Addr,
-    pub end: Ipv4Addr,
-    pub broadcast: Ipv4Addr,
-    pub hosts: u32,
+    pub start: T,
+    pub end: T,
+    pub broadcast: T,
+    pub hosts: T::Int,
+}
+
+/// Errors produced by [`Calculator::allocate_vlsm`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum AllocError {
+    /// The requirements, packed back-to-back, do not fit inside the parent network.
+    ExceedsParent,
+}
+
+impl std::fmt::Display for AllocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AllocError::ExceedsParent => {
+                write!(f, "requirements exceed the parent network's capacity")
+            }
+        }
+    }
 }
 
-impl Calculator {
+impl std::error::Error for AllocError {}
+
+impl<T: Ip> Calculator<T> {
     /// Calculates the number of bits needed to represent the given number of hosts.
     ///
     /// # Arguments
@@ -58,8 +83,8 @@ impl Calculator {
     /// # Returns
     ///
     /// The number of bits needed to represent the hosts.
-    fn calc_length(num_hosts: u32) -> u32 {
-        32 - num_hosts.next_power_of_two().trailing_zeros()
+    fn calc_length(num_hosts: T::Int) -> u32 {
+        T::BITS - T::int_trailing_zeros(T::int_next_power_of_two(num_hosts))
     }
 
     /// Calculates the address increment for a given subnet mask length.
@@ -71,8 +96,8 @@ impl Calculator {
     /// # Returns
     ///
     /// The address increment.
-    fn calc_increment(mask_len: u32) -> u32 {
-        1 << (32 - mask_len)
+    fn calc_increment(mask_len: u32) -> T::Int {
+        T::int_shl(T::int_one(), T::BITS - mask_len)
     }
 
     /// Generates a single subnet for the given network address and number of hosts.
@@ -85,17 +110,20 @@ impl Calculator {
     /// # Returns
     ///
     /// A tuple containing the generated `Subnet` and the next address.
-    pub fn generate_subnet(last_address: Ipv4Addr, num_hosts: u32) -> (Subnet, Ipv4Addr) {
+    pub fn generate_subnet(last_address: T, num_hosts: T::Int) -> (Subnet<T>, T) {
         let network_address = last_address;
         let required_subnet_mask_length = Self::calc_length(num_hosts);
         let subnet =
             Self::generate_subnet_from_address(network_address, required_subnet_mask_length);
-        let next_address = Ipv4Addr::from_u32(subnet.broadcast.to_u32() + 1);
+        let next_address = subnet.broadcast.saturating_add(T::int_one());
         (subnet, next_address)
     }
 
     /// Generates a subnet from the given network address and subnet mask length.
     ///
+    /// Uses saturating arithmetic throughout so a subnet anchored near the top of the address
+    /// space clamps instead of wrapping.
+    ///
     /// # Arguments
     ///
     /// * `network_address` - The network address.
@@ -104,13 +132,26 @@ impl Calculator {
     /// # Returns
     ///
     /// The generated `Subnet`.
-    fn generate_subnet_from_address(network_address: Ipv4Addr, subnet_mask_length: u32) -> Subnet {
+    fn generate_subnet_from_address(network_address: T, subnet_mask_length: u32) -> Subnet<T> {
         let address_increment = Self::calc_increment(subnet_mask_length);
-        let base_address = network_address.to_u32();
-        let usable_range_start = Ipv4Addr::from_u32(base_address + 1);
-        let broadcast_address = Ipv4Addr::from_u32(base_address + address_increment - 1);
-        let usable_range_end = Ipv4Addr::from_u32(broadcast_address.to_u32() - 1);
-        let hosts = address_increment - 2;
+        let broadcast_address =
+            network_address.saturating_add(T::int_sub(address_increment, T::int_one()));
+
+        let (usable_range_start, usable_range_end, hosts) = match T::VERSION {
+            // IPv6 has no broadcast convention: the whole block is usable.
+            IpVersion::V6 => (network_address, broadcast_address, address_increment),
+            IpVersion::V4 if subnet_mask_length == T::BITS => {
+                (network_address, network_address, T::int_one())
+            }
+            IpVersion::V4 if subnet_mask_length == T::BITS - 1 => {
+                (network_address, broadcast_address, T::int_add(T::int_one(), T::int_one()))
+            }
+            IpVersion::V4 => (
+                network_address.saturating_add(T::int_one()),
+                broadcast_address.saturating_sub(T::int_one()),
+                T::int_sub(address_increment, T::int_add(T::int_one(), T::int_one())),
+            ),
+        };
 
         Subnet {
             address: network_address,
@@ -123,9 +164,110 @@ impl Calculator {
     }
 }
 
+impl Calculator<Ipv4Addr> {
+    /// Returns an iterator over every usable host address in `subnet`.
+    ///
+    /// # Arguments
+    ///
+    /// * `subnet` - The subnet to list host addresses for.
+    ///
+    /// # Returns
+    ///
+    /// An `Ipv4AddrRange` yielding each address from `subnet.start` to
+    /// `subnet.end`, inclusive.
+    pub fn hosts(subnet: &Subnet<Ipv4Addr>) -> Ipv4AddrRange {
+        Ipv4AddrRange::new(subnet.start, subnet.end)
+    }
+
+    /// Allocates a subnet for each of `requirements` inside `parent`, using
+    /// VLSM (variable length subnet masking).
+    ///
+    /// Requirements are packed largest-first: sorting descending by host
+    /// count minimizes alignment padding between subnets, the same
+    /// heuristic used when laying out VLSM plans by hand. Subnets are
+    /// placed back-to-back starting at `parent`'s network address.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - The network to allocate subnets from.
+    /// * `requirements` - The number of hosts needed for each subnet, in
+    ///   the caller's desired order.
+    ///
+    /// # Returns
+    ///
+    /// The allocated `Subnet`s, in the same order as `requirements`, or
+    /// `AllocError::ExceedsParent` if they do not all fit inside `parent`.
+    pub fn allocate_vlsm(
+        parent: Ipv4Network,
+        requirements: &[u32],
+    ) -> Result<Vec<Subnet<Ipv4Addr>>, AllocError> {
+        let mut by_size: Vec<(usize, u32)> = requirements.iter().copied().enumerate().collect();
+        by_size.sort_by_key(|(_, num_hosts)| std::cmp::Reverse(*num_hosts));
+
+        // Tracked as `u64` rather than `Ipv4Addr` so overflow past
+        // `255.255.255.255` is still visible on the next iteration instead
+        // of being silently clamped away by `Ipv4Addr::saturating_add`.
+        let mut cursor: u64 = parent.network_address().to_u32() as u64;
+        let parent_broadcast = parent.broadcast().to_u32() as u64;
+        let mut allocated: Vec<(usize, Subnet<Ipv4Addr>)> = Vec::with_capacity(requirements.len());
+
+        for (original_index, num_hosts) in by_size {
+            let mask_length = Self::calc_length(num_hosts);
+            let address_increment = Self::calc_increment(mask_length) as u64;
+            let unsaturated_end = cursor + address_increment - 1;
+
+            if unsaturated_end > parent_broadcast {
+                return Err(AllocError::ExceedsParent);
+            }
+
+            let subnet = Self::generate_subnet_from_address(Ipv4Addr::from_u32(cursor as u32), mask_length);
+            cursor = unsaturated_end + 1;
+            allocated.push((original_index, subnet));
+        }
+
+        allocated.sort_by_key(|(original_index, _)| *original_index);
+        Ok(allocated.into_iter().map(|(_, subnet)| subnet).collect())
+    }
+
+    /// Returns the number of addresses in `parent` left unallocated after
+    /// `allocated`, assuming `allocated` was packed back-to-back from
+    /// `parent`'s network address as `allocate_vlsm` does.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - The network the subnets were allocated from.
+    /// * `allocated` - The subnets previously allocated from `parent`.
+    ///
+    /// # Returns
+    ///
+    /// The count of addresses from the one after the highest allocated
+    /// broadcast address up to `parent`'s broadcast address, inclusive.
+    pub fn remaining_capacity(parent: Ipv4Network, allocated: &[Subnet<Ipv4Addr>]) -> u32 {
+        // Tracked as `u64`, the same way `allocate_vlsm`'s `cursor` is, so a
+        // last allocation landing on `255.255.255.255` leaves `next_free` one
+        // past the top of the address space instead of being clamped back
+        // onto it and masquerading as one address of remaining capacity.
+        let next_free: u64 = allocated
+            .iter()
+            .map(|subnet| subnet.broadcast.to_u32() as u64)
+            .max()
+            .map_or(parent.network_address().to_u32() as u64, |broadcast| {
+                broadcast + 1
+            });
+        let parent_broadcast = parent.broadcast().to_u32() as u64;
+
+        if next_free > parent_broadcast {
+            0
+        } else {
+            (parent_broadcast - next_free + 1) as u32
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ipv6::Ipv6Addr;
 
     #[test]
     fn test_sequential_subnets() {
@@ -161,4 +303,118 @@ mod tests {
         assert_eq!(result3.broadcast, Ipv4Addr::new(192, 168, 0, 103));
         assert_eq!(result3.hosts, 6);
     }
+
+    #[test]
+    fn test_point_to_point_and_host_subnets() {
+        // A /31 is a point-to-point link: both addresses are usable.
+        let subnet31 = Calculator::generate_subnet_from_address(Ipv4Addr::new(10, 0, 0, 0), 31);
+        assert_eq!(subnet31.start, Ipv4Addr::new(10, 0, 0, 0));
+        assert_eq!(subnet31.end, Ipv4Addr::new(10, 0, 0, 1));
+        assert_eq!(subnet31.broadcast, Ipv4Addr::new(10, 0, 0, 1));
+        assert_eq!(subnet31.hosts, 2);
+
+        // A /32 is a single host with no broadcast address.
+        let subnet32 = Calculator::generate_subnet_from_address(Ipv4Addr::new(10, 0, 0, 5), 32);
+        assert_eq!(subnet32.start, Ipv4Addr::new(10, 0, 0, 5));
+        assert_eq!(subnet32.end, Ipv4Addr::new(10, 0, 0, 5));
+        assert_eq!(subnet32.broadcast, Ipv4Addr::new(10, 0, 0, 5));
+        assert_eq!(subnet32.hosts, 1);
+    }
+
+    #[test]
+    fn test_generate_subnet_saturates_near_top_of_address_space() {
+        let (subnet, next_address) =
+            Calculator::generate_subnet(Ipv4Addr::new(255, 255, 255, 250), 10);
+        assert_eq!(subnet.broadcast, Ipv4Addr::new(255, 255, 255, 255));
+        assert_eq!(next_address, Ipv4Addr::new(255, 255, 255, 255));
+    }
+
+    #[test]
+    fn test_hosts_iterates_usable_range() {
+        let (subnet, _) = Calculator::generate_subnet(Ipv4Addr::new(192, 168, 0, 0), 5);
+        let hosts: Vec<Ipv4Addr> = Calculator::hosts(&subnet).collect();
+        assert_eq!(hosts.first(), Some(&subnet.start));
+        assert_eq!(hosts.last(), Some(&subnet.end));
+        assert_eq!(hosts.len(), subnet.hosts as usize);
+    }
+
+    #[test]
+    fn test_allocate_vlsm_packs_largest_first_but_preserves_order() {
+        let parent = Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 24).unwrap();
+
+        // Out of order and of mixed sizes; 50 should be packed first even
+        // though it isn't first in the input.
+        let requirements = [20, 50, 5];
+        let allocated = Calculator::allocate_vlsm(parent, &requirements).unwrap();
+
+        assert_eq!(allocated.len(), 3);
+
+        // Result order matches the caller's input order.
+        assert_eq!(allocated[0].hosts, 30); // covers the 20-host requirement
+        assert_eq!(allocated[1].hosts, 62); // covers the 50-host requirement
+        assert_eq!(allocated[2].hosts, 6); // covers the 5-host requirement
+
+        // But the 50-host block was packed first, at the parent's base address.
+        assert_eq!(allocated[1].address, Ipv4Addr::new(192, 168, 0, 0));
+        assert_eq!(allocated[0].address, Ipv4Addr::new(192, 168, 0, 64));
+        assert_eq!(allocated[2].address, Ipv4Addr::new(192, 168, 0, 96));
+
+        let remaining = Calculator::remaining_capacity(parent, &allocated);
+        assert_eq!(remaining, 256 - 64 - 32 - 8);
+    }
+
+    #[test]
+    fn test_allocate_vlsm_rejects_requirements_that_overflow_parent() {
+        let parent = Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 28).unwrap(); // 16 addresses
+        let requirements = [50];
+
+        assert_eq!(
+            Calculator::allocate_vlsm(parent, &requirements),
+            Err(AllocError::ExceedsParent)
+        );
+    }
+
+    #[test]
+    fn test_allocate_vlsm_rejects_overflow_near_top_of_address_space() {
+        // The parent is saturated at 255.255.255.255, so a naive check
+        // against the saturated broadcast address would let the second
+        // requirement overlap the first instead of being rejected.
+        let parent = Ipv4Network::new(Ipv4Addr::new(255, 255, 255, 0), 24).unwrap();
+        let requirements = [254, 1];
+
+        assert_eq!(
+            Calculator::allocate_vlsm(parent, &requirements),
+            Err(AllocError::ExceedsParent)
+        );
+    }
+
+    #[test]
+    fn test_remaining_capacity_is_zero_when_last_subnet_reaches_top_of_address_space() {
+        // The parent is fully allocated out to 255.255.255.255; a naive
+        // check against the saturated broadcast address would report 1
+        // address free instead of 0.
+        let parent = Ipv4Network::new(Ipv4Addr::new(255, 255, 255, 254), 31).unwrap();
+        let requirements = [2];
+
+        let allocated = Calculator::allocate_vlsm(parent, &requirements).unwrap();
+        assert_eq!(allocated[0].broadcast, Ipv4Addr::new(255, 255, 255, 255));
+        assert_eq!(Calculator::remaining_capacity(parent, &allocated), 0);
+    }
+
+    #[test]
+    fn test_ipv6_subnet_has_no_broadcast_convention() {
+        let initial_address = Ipv6Addr::new([0x2001, 0x0db8, 0, 0, 0, 0, 0, 0]);
+
+        // A /125 fits 8 addresses, all of them usable (no network/broadcast reservation).
+        let (subnet, next_address) = Calculator::generate_subnet(initial_address, 8u128);
+        assert_eq!(subnet.mask_length, 125);
+        assert_eq!(subnet.start, initial_address);
+        assert_eq!(
+            subnet.end,
+            Ipv6Addr::new([0x2001, 0x0db8, 0, 0, 0, 0, 0, 7])
+        );
+        assert_eq!(subnet.broadcast, subnet.end);
+        assert_eq!(subnet.hosts, 8);
+        assert_eq!(next_address, Ipv6Addr::new([0x2001, 0x0db8, 0, 0, 0, 0, 0, 8]));
+    }
 }